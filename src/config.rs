@@ -0,0 +1,2 @@
+/// File extensions that `get_files` will read and hand off to the parser.
+pub const ALLOWED_EXTENSIONS: [&str; 2] = ["graphql", "gql"];
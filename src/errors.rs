@@ -0,0 +1,22 @@
+use async_std::path::PathBuf;
+use std::fmt;
+
+/// Wraps a `graphql_parser` failure with the file it came from, since the
+/// parser itself only knows about an offset into the string it was given.
+#[derive(Debug)]
+pub struct ParseFileError {
+    pub file: PathBuf,
+    pub source: graphql_parser::schema::ParseError,
+}
+
+impl fmt::Display for ParseFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.file.display(), self.source)
+    }
+}
+
+impl std::error::Error for ParseFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
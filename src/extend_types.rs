@@ -0,0 +1,125 @@
+use graphql_parser::schema;
+
+/// Pulls the list of type names a schema definition refers to, so the graph
+/// builder can wire up edges without re-walking each definition's shape.
+pub trait ExtendType {
+    fn get_dependencies(&self) -> Vec<String>;
+}
+
+fn base_type_name(field_type: &schema::Type<String>) -> String {
+    match field_type {
+        schema::Type::NamedType(name) => name.clone(),
+        schema::Type::ListType(inner) => base_type_name(inner),
+        schema::Type::NonNullType(inner) => base_type_name(inner),
+    }
+}
+
+fn field_dependencies(fields: &[schema::Field<String>]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| base_type_name(&field.field_type))
+        .collect()
+}
+
+fn input_value_dependencies(input_values: &[schema::InputValue<String>]) -> Vec<String> {
+    input_values
+        .iter()
+        .map(|input_value| base_type_name(&input_value.value_type))
+        .collect()
+}
+
+impl ExtendType for schema::EnumType<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        // Enum values are plain strings, not type references.
+        Vec::new()
+    }
+}
+
+impl ExtendType for schema::EnumTypeExtension<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl ExtendType for schema::ScalarType<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl ExtendType for schema::ScalarTypeExtension<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl ExtendType for schema::InputObjectType<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        input_value_dependencies(&self.fields)
+    }
+}
+
+impl ExtendType for schema::InputObjectTypeExtension<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        input_value_dependencies(&self.fields)
+    }
+}
+
+impl ExtendType for schema::InterfaceType<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        field_dependencies(&self.fields)
+    }
+}
+
+impl ExtendType for schema::InterfaceTypeExtension<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        field_dependencies(&self.fields)
+    }
+}
+
+impl ExtendType for schema::ObjectType<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        let mut dependencies = self.implements_interfaces.clone();
+        dependencies.extend(field_dependencies(&self.fields));
+        dependencies
+    }
+}
+
+impl ExtendType for schema::ObjectTypeExtension<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        let mut dependencies = self.implements_interfaces.clone();
+        dependencies.extend(field_dependencies(&self.fields));
+        dependencies
+    }
+}
+
+impl ExtendType for schema::UnionType<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        self.types.clone()
+    }
+}
+
+impl ExtendType for schema::UnionTypeExtension<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        self.types.clone()
+    }
+}
+
+impl ExtendType for schema::DirectiveDefinition<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        input_value_dependencies(&self.arguments)
+    }
+}
+
+impl ExtendType for schema::SchemaDefinition<String> {
+    fn get_dependencies(&self) -> Vec<String> {
+        vec![
+            self.query.clone(),
+            self.mutation.clone(),
+            self.subscription.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
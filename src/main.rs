@@ -0,0 +1,72 @@
+mod config;
+mod errors;
+mod export;
+mod extend_types;
+mod state;
+mod utils;
+mod validation;
+mod watch;
+
+use anyhow::Result;
+use async_std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    task,
+};
+use state::Data;
+use std::time::Duration;
+use utils::{get_files, populate_graph_from_ast};
+
+fn main() -> Result<()> {
+    task::block_on(run())
+}
+
+async fn run() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let watch_mode = args.iter().any(|arg| arg == "--watch");
+    let json_mode = args.iter().any(|arg| arg == "--json");
+    let path = args
+        .iter()
+        .find(|arg| *arg != "--watch" && *arg != "--json")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    let shared_data = Arc::new(Mutex::new(Data::default()));
+
+    if watch_mode {
+        return watch::watch(path, shared_data, Duration::from_secs(1)).await;
+    }
+
+    get_files(path, shared_data.clone()).await?;
+    populate_graph_from_ast(shared_data.clone()).await?;
+
+    let data = shared_data.lock().await;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&export::export(&data))?);
+        return Ok(());
+    }
+
+    println!(
+        "Parsed {} definitions across {} files.",
+        data.graph.node_count(),
+        data.files.len()
+    );
+
+    if let Some(subscription_root) = data.subscription_root() {
+        println!("Subscription root: {}", data.graph[subscription_root].id);
+    }
+
+    let diagnostics = validation::validate(&data);
+    drop(data);
+
+    for diagnostic in &diagnostics {
+        eprintln!("{}", diagnostic);
+    }
+
+    if !diagnostics.is_empty() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
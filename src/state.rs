@@ -0,0 +1,127 @@
+use async_std::path::PathBuf;
+use graphql_parser::Pos;
+use petgraph::graph::{Graph, NodeIndex};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// The concrete GraphQL type a `TypeDefinition` or `TypeExtension` describes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum GraphQLType {
+    Enum,
+    InputObject,
+    Interface,
+    Object,
+    Scalar,
+    Union,
+}
+
+/// The kind of GraphQL definition a node in the graph represents.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum GraphQL {
+    Schema,
+    Directive,
+    TypeDefinition(GraphQLType),
+    TypeExtension(GraphQLType),
+}
+
+/// What an edge in the graph means: a plain type reference, or one of the
+/// three root operations hanging off a `GraphQL::Schema` node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum EdgeKind {
+    Dependency,
+    Query,
+    Mutation,
+    Subscription,
+}
+
+/// The root operation types declared by a `schema { ... }` block, by name.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RootOperations {
+    pub query: Option<String>,
+    pub mutation: Option<String>,
+    pub subscription: Option<String>,
+}
+
+/// Everything we know about a single GraphQL definition: what it is, where it
+/// came from, and what other type names it depends on.
+///
+/// Not `Serialize`: `file` is an `async_std::path::PathBuf`, which has no
+/// serde impl. `export::export` builds its own serializable shape
+/// (`NodeExport`) from this instead of deriving it here.
+#[derive(Debug, Clone)]
+pub struct Entity {
+    pub dependencies: Vec<String>,
+    pub graphql: GraphQL,
+    pub name: String,
+    pub file: PathBuf,
+    pub contents: String,
+    /// Where this definition starts in `file`. `None` for nodes synthesized
+    /// by the graph builder rather than parsed straight out of a file.
+    pub position: Option<Pos>,
+    /// Populated only for `GraphQL::Schema` entities: the query/mutation/
+    /// subscription root types it declares.
+    pub roots: Option<RootOperations>,
+}
+
+impl Entity {
+    pub fn new(
+        dependencies: Vec<String>,
+        graphql: GraphQL,
+        name: String,
+        file: PathBuf,
+        contents: String,
+        position: Option<Pos>,
+        roots: Option<RootOperations>,
+    ) -> Self {
+        Entity {
+            dependencies,
+            graphql,
+            name,
+            file,
+            contents,
+            position,
+            roots,
+        }
+    }
+}
+
+/// A node in the dependency graph, keyed by the id it was inserted under
+/// (the definition name, or `{Name}Ext` for type extensions).
+///
+/// Not `Serialize`, since its `Entity` isn't - see `Entity`'s doc comment.
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub id: String,
+    pub entity: Entity,
+}
+
+impl Node {
+    pub fn new(entity: Entity, id: String) -> Self {
+        Node { id, entity }
+    }
+}
+
+/// Shared state built up while walking the schema files: the raw file
+/// contents and the dependency graph parsed out of them.
+#[derive(Default)]
+pub struct Data {
+    pub files: HashMap<PathBuf, String>,
+    pub graph: Graph<Node, EdgeKind>,
+}
+
+impl Data {
+    /// The node a `Schema` definition points to via the given root edge
+    /// kind, if any node has one.
+    fn root(&self, kind: EdgeKind) -> Option<NodeIndex> {
+        self.graph.edge_indices().find_map(|edge| {
+            let (_, target) = self.graph.edge_endpoints(edge)?;
+            (self.graph[edge] == kind).then_some(target)
+        })
+    }
+
+    /// The schema's subscription root type, if any - the starting point for
+    /// answering "what's reachable from subscriptions?".
+    pub fn subscription_root(&self) -> Option<NodeIndex> {
+        self.root(EdgeKind::Subscription)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::config::ALLOWED_EXTENSIONS;
+use crate::errors::ParseFileError;
 use crate::extend_types::ExtendType;
-use crate::state::{Data, Entity, GraphQL, GraphQLType, Node};
+use crate::state::{Data, EdgeKind, Entity, GraphQL, GraphQLType, Node, RootOperations};
 
 use anyhow::Result;
 use async_std::{
@@ -10,6 +11,7 @@ use async_std::{
     pin::Pin,
     prelude::*,
     sync::{Arc, Mutex},
+    task,
 };
 use graphql_parser::{parse_schema, schema};
 use petgraph::graph::NodeIndex;
@@ -19,6 +21,16 @@ fn is_extension_allowed(extension: &str) -> bool {
     ALLOWED_EXTENSIONS.to_vec().contains(&extension)
 }
 
+/// Whether `get_files` should read this path: it must have an extension
+/// (editor swap files, `.DS_Store`, etc. don't) and that extension must be
+/// one of `ALLOWED_EXTENSIONS`.
+fn has_allowed_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .map(is_extension_allowed)
+        .unwrap_or(false)
+}
+
 fn get_extended_id(id: String) -> String {
     format!("{}Ext", id)
 }
@@ -35,13 +47,7 @@ pub fn get_files(
         let file_type = file_or_dir.file_type();
 
         if file_type.is_file() {
-            if is_extension_allowed(
-                Path::new(thread_safe_path.as_ref())
-                    .extension()
-                    .unwrap()
-                    .to_str()
-                    .unwrap(),
-            ) {
+            if has_allowed_extension(Path::new(thread_safe_path.as_ref())) {
                 let contents = fs::read_to_string(thread_safe_path.as_ref()).await?;
                 let mut data = shared_data.lock().await;
 
@@ -61,7 +67,7 @@ pub fn get_files(
             let metadata = entry.clone().metadata().await?;
             let is_dir = metadata.is_dir();
 
-            if !is_dir && is_extension_allowed(&inner_path.extension().unwrap().to_str().unwrap()) {
+            if !is_dir && has_allowed_extension(&inner_path) {
                 let contents = fs::read_to_string(inner_path).await?;
                 let mut data = shared_data.lock().await;
 
@@ -75,284 +81,385 @@ pub fn get_files(
     })
 }
 
-/// Parse the files, generate an AST and walk it to populate the graph.
-pub async fn populate_graph_from_ast(shared_data: Arc<Mutex<Data>>) -> Result<()> {
-    let mut data = shared_data.lock().await;
-    let files = &data.files.clone();
-    // Keep track of the dependencies for edges.
-    let mut dependency_hash_map: HashMap<NodeIndex, Vec<String>> = HashMap::new();
-
-    // Populate the nodes first.
-    for (file, contents) in files {
-        let ast = parse_schema::<String>(contents.as_str())?;
-
-        // Reference: http://spec.graphql.org/draft/
-        for definition in ast.definitions {
-            match definition {
-                schema::Definition::TypeDefinition(type_definition) => match type_definition {
-                    schema::TypeDefinition::Enum(enum_type) => {
-                        let id = enum_type.name.clone();
-                        let dependencies = enum_type.get_dependencies();
-
-                        let node_index = data.graph.add_node(Node::new(
-                            Entity::new(
-                                dependencies.clone(), // Enums don't have dependencies.
-                                GraphQL::TypeDefinition(GraphQLType::Enum),
-                                enum_type.name,
-                                file.to_owned(),
-                                contents.to_owned(),
-                            ),
-                            id,
-                        ));
-
-                        // Update dependencies.
-                        dependency_hash_map.insert(node_index, dependencies);
-                    }
-
-                    schema::TypeDefinition::InputObject(input_object_type) => {
-                        let id = input_object_type.name.clone();
-                        let dependencies = input_object_type.get_dependencies();
-
-                        let node_index = data.graph.add_node(Node::new(
-                            Entity::new(
-                                dependencies.clone(),
-                                GraphQL::TypeDefinition(GraphQLType::InputObject),
-                                input_object_type.name,
-                                file.to_owned(),
-                                contents.to_owned(),
-                            ),
-                            id,
-                        ));
-
-                        dependency_hash_map.insert(node_index, dependencies);
-                    }
-
-                    schema::TypeDefinition::Interface(interface_type) => {
-                        let id = interface_type.name.clone();
-                        let dependencies = interface_type.get_dependencies();
-
-                        let node_index = data.graph.add_node(Node::new(
-                            Entity::new(
-                                dependencies.clone(),
-                                GraphQL::TypeDefinition(GraphQLType::Interface),
-                                interface_type.name,
-                                file.to_owned(),
-                                contents.to_owned(),
-                            ),
-                            id,
-                        ));
-
-                        dependency_hash_map.insert(node_index, dependencies);
-                    }
-
-                    schema::TypeDefinition::Object(object_type) => {
-                        let id = object_type.name.clone();
-                        let dependencies = object_type.get_dependencies();
-
-                        let node_index = data.graph.add_node(Node::new(
-                            Entity::new(
-                                dependencies.clone(),
-                                GraphQL::TypeDefinition(GraphQLType::Object),
-                                object_type.name,
-                                file.to_owned(),
-                                contents.to_owned(),
-                            ),
-                            id,
-                        ));
-
-                        dependency_hash_map.insert(node_index, dependencies);
-                    }
-
-                    schema::TypeDefinition::Scalar(scalar_type) => {
-                        let id = scalar_type.name.clone();
-                        let dependencies = scalar_type.get_dependencies();
-
-                        let node_index = data.graph.add_node(Node::new(
-                            Entity::new(
-                                dependencies.clone(),
-                                GraphQL::TypeDefinition(GraphQLType::Scalar),
-                                scalar_type.name,
-                                file.to_owned(),
-                                contents.to_owned(),
-                            ),
-                            id,
-                        ));
-
-                        dependency_hash_map.insert(node_index, dependencies);
-                    }
-
-                    schema::TypeDefinition::Union(union_type) => {
-                        let id = union_type.name.clone();
-                        let dependencies = union_type.get_dependencies();
-
-                        let node_index = data.graph.add_node(Node::new(
-                            Entity::new(
-                                dependencies.clone(),
-                                GraphQL::TypeDefinition(GraphQLType::Union),
-                                union_type.name,
-                                file.to_owned(),
-                                contents.to_owned(),
-                            ),
-                            id,
-                        ));
-
-                        dependency_hash_map.insert(node_index, dependencies);
-                    }
-                },
-
-                schema::Definition::SchemaDefinition(schema_definition) => {
-                    // A Schema has no name, use a default one.
-                    let id = String::from("Schema");
-                    let dependencies = schema_definition.get_dependencies();
-
-                    let node_index = data.graph.add_node(Node::new(
+/// Walks a single file's AST and builds the entities it defines, without
+/// touching the shared graph. Kept as a free function (rather than inline
+/// in `populate_graph_from_ast`) so it can run standalone inside a spawned
+/// task, one per file.
+fn entities_from_definitions(
+    file: &PathBuf,
+    contents: &str,
+    definitions: Vec<schema::Definition<String>>,
+) -> Vec<(String, Entity)> {
+    let mut entities = Vec::new();
+
+    // Reference: http://spec.graphql.org/draft/
+    for definition in definitions {
+        match definition {
+            schema::Definition::TypeDefinition(type_definition) => match type_definition {
+                schema::TypeDefinition::Enum(enum_type) => {
+                    let id = enum_type.name.clone();
+                    let dependencies = enum_type.get_dependencies(); // Enums don't have dependencies.
+                    let position = enum_type.position;
+
+                    entities.push((
+                        id,
                         Entity::new(
                             dependencies.clone(),
-                            GraphQL::Schema,
-                            String::from("Schema"),
+                            GraphQL::TypeDefinition(GraphQLType::Enum),
+                            enum_type.name,
                             file.to_owned(),
                             contents.to_owned(),
+                            Some(position),
+                            None,
                         ),
-                        id,
                     ));
+                }
+
+                schema::TypeDefinition::InputObject(input_object_type) => {
+                    let id = input_object_type.name.clone();
+                    let dependencies = input_object_type.get_dependencies();
+                    let position = input_object_type.position;
 
-                    dependency_hash_map.insert(node_index, dependencies);
+                    entities.push((
+                        id,
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeDefinition(GraphQLType::InputObject),
+                            input_object_type.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
                 }
 
-                schema::Definition::DirectiveDefinition(directive_definition) => {
-                    let id = directive_definition.name.clone();
-                    let dependencies = directive_definition.get_dependencies();
+                schema::TypeDefinition::Interface(interface_type) => {
+                    let id = interface_type.name.clone();
+                    let dependencies = interface_type.get_dependencies();
+                    let position = interface_type.position;
 
-                    let node_index = data.graph.add_node(Node::new(
+                    entities.push((
+                        id,
                         Entity::new(
                             dependencies.clone(),
-                            GraphQL::Directive,
-                            directive_definition.name,
+                            GraphQL::TypeDefinition(GraphQLType::Interface),
+                            interface_type.name,
                             file.to_owned(),
                             contents.to_owned(),
+                            Some(position),
+                            None,
                         ),
+                    ));
+                }
+
+                schema::TypeDefinition::Object(object_type) => {
+                    let id = object_type.name.clone();
+                    let dependencies = object_type.get_dependencies();
+                    let position = object_type.position;
+
+                    entities.push((
                         id,
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeDefinition(GraphQLType::Object),
+                            object_type.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
                     ));
+                }
 
-                    dependency_hash_map.insert(node_index, dependencies);
+                schema::TypeDefinition::Scalar(scalar_type) => {
+                    let id = scalar_type.name.clone();
+                    let dependencies = scalar_type.get_dependencies();
+                    let position = scalar_type.position;
+
+                    entities.push((
+                        id,
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeDefinition(GraphQLType::Scalar),
+                            scalar_type.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
                 }
 
-                schema::Definition::TypeExtension(type_extension) => {
-                    match type_extension {
-                        schema::TypeExtension::Object(object_type_extension) => {
-                            let id = object_type_extension.name.clone();
-                            let dependencies = object_type_extension.get_dependencies();
-
-                            let node_index = data.graph.add_node(Node::new(
-                                Entity::new(
-                                    dependencies.clone(),
-                                    GraphQL::TypeExtension(GraphQLType::Object),
-                                    object_type_extension.name,
-                                    file.to_owned(),
-                                    contents.to_owned(),
-                                ),
-                                get_extended_id(id),
-                            ));
-
-                            dependency_hash_map.insert(node_index, dependencies);
-                        }
-
-                        schema::TypeExtension::Scalar(scalar_type_extension) => {
-                            let id = scalar_type_extension.name.clone();
-                            let dependencies = scalar_type_extension.get_dependencies();
-
-                            let node_index = data.graph.add_node(Node::new(
-                                Entity::new(
-                                    dependencies.clone(),
-                                    GraphQL::TypeExtension(GraphQLType::Scalar),
-                                    scalar_type_extension.name,
-                                    file.to_owned(),
-                                    contents.to_owned(),
-                                ),
-                                get_extended_id(id),
-                            ));
-
-                            dependency_hash_map.insert(node_index, dependencies);
-                        }
-
-                        schema::TypeExtension::Interface(interface_type_extension) => {
-                            let id = interface_type_extension.name.clone();
-                            let dependencies = interface_type_extension.get_dependencies();
-
-                            let node_index = data.graph.add_node(Node::new(
-                                Entity::new(
-                                    dependencies.clone(),
-                                    GraphQL::TypeExtension(GraphQLType::Scalar),
-                                    interface_type_extension.name,
-                                    file.to_owned(),
-                                    contents.to_owned(),
-                                ),
-                                get_extended_id(id),
-                            ));
-
-                            dependency_hash_map.insert(node_index, dependencies);
-                        }
-
-                        schema::TypeExtension::Union(union_type_extension) => {
-                            let id = union_type_extension.name.clone();
-                            let dependencies = union_type_extension.get_dependencies();
-
-                            let node_index = data.graph.add_node(Node::new(
-                                Entity::new(
-                                    dependencies.clone(),
-                                    GraphQL::TypeExtension(GraphQLType::Union),
-                                    union_type_extension.name,
-                                    file.to_owned(),
-                                    contents.to_owned(),
-                                ),
-                                get_extended_id(id),
-                            ));
-
-                            dependency_hash_map.insert(node_index, dependencies);
-                        }
-
-                        schema::TypeExtension::Enum(enum_type_extension) => {
-                            let id = enum_type_extension.name.clone();
-                            let dependencies = enum_type_extension.get_dependencies();
-
-                            let node_index = data.graph.add_node(Node::new(
-                                Entity::new(
-                                    dependencies.clone(),
-                                    GraphQL::TypeExtension(GraphQLType::Enum),
-                                    enum_type_extension.name,
-                                    file.to_owned(),
-                                    contents.to_owned(),
-                                ),
-                                get_extended_id(id),
-                            ));
-
-                            dependency_hash_map.insert(node_index, dependencies);
-                        }
-
-                        schema::TypeExtension::InputObject(input_object_type_extension) => {
-                            let id = input_object_type_extension.name.clone();
-                            let dependencies = input_object_type_extension.get_dependencies();
-
-                            let node_index = data.graph.add_node(Node::new(
-                                Entity::new(
-                                    dependencies.clone(),
-                                    GraphQL::TypeExtension(GraphQLType::InputObject),
-                                    input_object_type_extension.name,
-                                    file.to_owned(),
-                                    contents.to_owned(),
-                                ),
-                                get_extended_id(id),
-                            ));
-
-                            dependency_hash_map.insert(node_index, dependencies);
-                        }
-                    };
+                schema::TypeDefinition::Union(union_type) => {
+                    let id = union_type.name.clone();
+                    let dependencies = union_type.get_dependencies();
+                    let position = union_type.position;
+
+                    entities.push((
+                        id,
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeDefinition(GraphQLType::Union),
+                            union_type.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
                 }
+            },
+
+            schema::Definition::SchemaDefinition(schema_definition) => {
+                // A Schema has no name, use a default one.
+                let id = String::from("Schema");
+                let dependencies = schema_definition.get_dependencies();
+                let position = schema_definition.position;
+                let roots = RootOperations {
+                    query: schema_definition.query.clone(),
+                    mutation: schema_definition.mutation.clone(),
+                    subscription: schema_definition.subscription.clone(),
+                };
+
+                entities.push((
+                    id,
+                    Entity::new(
+                        dependencies.clone(),
+                        GraphQL::Schema,
+                        String::from("Schema"),
+                        file.to_owned(),
+                        contents.to_owned(),
+                        Some(position),
+                        Some(roots),
+                    ),
+                ));
             }
+
+            schema::Definition::DirectiveDefinition(directive_definition) => {
+                let id = directive_definition.name.clone();
+                let dependencies = directive_definition.get_dependencies();
+                let position = directive_definition.position;
+
+                entities.push((
+                    id,
+                    Entity::new(
+                        dependencies.clone(),
+                        GraphQL::Directive,
+                        directive_definition.name,
+                        file.to_owned(),
+                        contents.to_owned(),
+                        Some(position),
+                        None,
+                    ),
+                ));
+            }
+
+            schema::Definition::TypeExtension(type_extension) => match type_extension {
+                schema::TypeExtension::Object(object_type_extension) => {
+                    let id = object_type_extension.name.clone();
+                    let dependencies = object_type_extension.get_dependencies();
+                    let position = object_type_extension.position;
+
+                    entities.push((
+                        get_extended_id(id),
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeExtension(GraphQLType::Object),
+                            object_type_extension.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
+                }
+
+                schema::TypeExtension::Scalar(scalar_type_extension) => {
+                    let id = scalar_type_extension.name.clone();
+                    let dependencies = scalar_type_extension.get_dependencies();
+                    let position = scalar_type_extension.position;
+
+                    entities.push((
+                        get_extended_id(id),
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeExtension(GraphQLType::Scalar),
+                            scalar_type_extension.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
+                }
+
+                schema::TypeExtension::Interface(interface_type_extension) => {
+                    let id = interface_type_extension.name.clone();
+                    let dependencies = interface_type_extension.get_dependencies();
+                    let position = interface_type_extension.position;
+
+                    entities.push((
+                        get_extended_id(id),
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeExtension(GraphQLType::Interface),
+                            interface_type_extension.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
+                }
+
+                schema::TypeExtension::Union(union_type_extension) => {
+                    let id = union_type_extension.name.clone();
+                    let dependencies = union_type_extension.get_dependencies();
+                    let position = union_type_extension.position;
+
+                    entities.push((
+                        get_extended_id(id),
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeExtension(GraphQLType::Union),
+                            union_type_extension.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
+                }
+
+                schema::TypeExtension::Enum(enum_type_extension) => {
+                    let id = enum_type_extension.name.clone();
+                    let dependencies = enum_type_extension.get_dependencies();
+                    let position = enum_type_extension.position;
+
+                    entities.push((
+                        get_extended_id(id),
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeExtension(GraphQLType::Enum),
+                            enum_type_extension.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
+                }
+
+                schema::TypeExtension::InputObject(input_object_type_extension) => {
+                    let id = input_object_type_extension.name.clone();
+                    let dependencies = input_object_type_extension.get_dependencies();
+                    let position = input_object_type_extension.position;
+
+                    entities.push((
+                        get_extended_id(id),
+                        Entity::new(
+                            dependencies.clone(),
+                            GraphQL::TypeExtension(GraphQLType::InputObject),
+                            input_object_type_extension.name,
+                            file.to_owned(),
+                            contents.to_owned(),
+                            Some(position),
+                            None,
+                        ),
+                    ));
+                }
+            },
         }
     }
 
-    // Populate the edges.
+    entities
+}
+
+/// Parse the files, generate an AST and walk it to populate the graph.
+///
+/// Parsing runs as one task per file, each producing its own entities
+/// without touching `shared_data`, so CPU-bound parsing scales across
+/// cores instead of serializing behind the graph's mutex. Once every task
+/// has finished, a single-threaded merge phase adds the nodes (preserving
+/// the same ids as a serial run would) before edges are resolved.
+pub async fn populate_graph_from_ast(shared_data: Arc<Mutex<Data>>) -> Result<()> {
+    let files = shared_data.lock().await.files.clone();
+
+    add_nodes_from_files(&shared_data, files).await?;
+
+    let mut data = shared_data.lock().await;
+    rebuild_edges(&mut data);
+
+    Ok(())
+}
+
+/// Parses just the given `files` and adds the entities they define as new
+/// nodes in `shared_data`'s graph. Used both by `populate_graph_from_ast`
+/// (with every known file) and by watch-mode rebuilds (with only the files
+/// whose contents changed), so edges can be (re)computed once afterwards
+/// instead of per file.
+pub(crate) async fn add_nodes_from_files(
+    shared_data: &Arc<Mutex<Data>>,
+    files: HashMap<PathBuf, String>,
+) -> Result<()> {
+    let parse_tasks: Vec<_> = files
+        .into_iter()
+        .map(|(file, contents)| {
+            task::spawn(async move {
+                let ast =
+                    parse_schema::<String>(contents.as_str()).map_err(|source| ParseFileError {
+                        file: file.clone(),
+                        source,
+                    })?;
+                Ok::<_, anyhow::Error>(entities_from_definitions(&file, &contents, ast.definitions))
+            })
+        })
+        .collect();
+
+    let mut parsed_entities = Vec::new();
+    for parse_task in parse_tasks {
+        parsed_entities.extend(parse_task.await?);
+    }
+
+    // Merge phase: single-threaded so node insertion order (and therefore
+    // node indices) matches what a serial parse would have produced.
+    let mut data = shared_data.lock().await;
+
+    for (id, entity) in parsed_entities {
+        data.graph.add_node(Node::new(entity, id));
+    }
+
+    Ok(())
+}
+
+/// Whether `node` is the schema node synthesized by `resolve_root_operations`
+/// from conventionally-named root types, rather than parsed from a real
+/// `schema { ... }` block. Synthetic nodes are tagged with an empty file
+/// path, which no parsed definition can ever report, so this is an
+/// unambiguous marker.
+fn is_synthetic_schema(node: &Node) -> bool {
+    node.entity.graphql == GraphQL::Schema && node.entity.file == PathBuf::new()
+}
+
+fn find_node_by_id(data: &Data, id: &str) -> Option<NodeIndex> {
+    data.graph
+        .node_indices()
+        .find(|index| data.graph[*index].id == id)
+}
+
+/// Recomputes every edge in the graph from the dependency list and root
+/// operations each node's `Entity` already carries. Safe to call any time
+/// nodes are added or removed (e.g. after an incremental rebuild), since
+/// `update_edge` is idempotent and dangling edges are never inserted.
+pub(crate) fn rebuild_edges(data: &mut Data) {
+    // Keep track of the dependencies for edges.
+    let dependency_hash_map: HashMap<NodeIndex, Vec<String>> = data
+        .graph
+        .node_indices()
+        .map(|index| (index, data.graph[index].entity.dependencies.clone()))
+        .collect();
+
     for (node_index, dependencies) in dependency_hash_map {
         for dependency in dependencies {
             // https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.Graph.html#method.node_indices
@@ -364,10 +471,304 @@ pub async fn populate_graph_from_ast(shared_data: Arc<Mutex<Data>>) -> Result<()
             if let Some(index) = *maybe_index {
                 &data
                     .graph
-                    .update_edge(index, node_index, (index, node_index));
+                    .update_edge(index, node_index, EdgeKind::Dependency);
             }
         }
     }
 
-    Ok(())
+    resolve_root_operations(data);
+}
+
+/// Adds a labeled edge from a `Schema` node to the root type it names, if
+/// that type exists in the graph.
+fn add_root_edge(data: &mut Data, schema_index: NodeIndex, root: Option<&str>, kind: EdgeKind) {
+    let root_index = root.and_then(|root| find_node_by_id(data, root));
+
+    if let Some(root_index) = root_index {
+        data.graph.update_edge(schema_index, root_index, kind);
+    }
+}
+
+/// Wires up query/mutation/subscription edges from every `Schema` node to
+/// the root types it names. If no file declared a `schema { ... }` block at
+/// all, synthesize one from the conventionally-named `Query`/`Mutation`/
+/// `Subscription` types, per the GraphQL spec's default root operation
+/// types.
+fn resolve_root_operations(data: &mut Data) {
+    // Drop any previously-synthesized implicit schema node before
+    // recomputing roots. Without this, a synthetic node's `roots` makes it
+    // look "declared" on the next rebuild, so a file that later adds a real
+    // `schema { ... }` block (or a rename that removes the last
+    // conventionally-named root type) would leave the stale synthetic node
+    // wired in alongside, instead of replaced by, the real one.
+    if let Some(index) = data
+        .graph
+        .node_indices()
+        .find(|index| is_synthetic_schema(&data.graph[*index]))
+    {
+        data.graph.remove_node(index);
+    }
+
+    let declared_roots: Vec<(NodeIndex, RootOperations)> = data
+        .graph
+        .node_indices()
+        .filter_map(|index| {
+            data.graph[index]
+                .entity
+                .roots
+                .clone()
+                .map(|roots| (index, roots))
+        })
+        .collect();
+
+    if declared_roots.is_empty() {
+        let implicit_roots = RootOperations {
+            query: find_node_by_id(data, "Query").map(|_| String::from("Query")),
+            mutation: find_node_by_id(data, "Mutation").map(|_| String::from("Mutation")),
+            subscription: find_node_by_id(data, "Subscription")
+                .map(|_| String::from("Subscription")),
+        };
+
+        if implicit_roots.query.is_none()
+            && implicit_roots.mutation.is_none()
+            && implicit_roots.subscription.is_none()
+        {
+            return;
+        }
+
+        let schema_index = data.graph.add_node(Node::new(
+            Entity::new(
+                Vec::new(),
+                GraphQL::Schema,
+                String::from("Schema"),
+                PathBuf::new(),
+                String::new(),
+                None,
+                Some(implicit_roots.clone()),
+            ),
+            String::from("Schema"),
+        ));
+
+        add_root_edge(
+            data,
+            schema_index,
+            implicit_roots.query.as_deref(),
+            EdgeKind::Query,
+        );
+        add_root_edge(
+            data,
+            schema_index,
+            implicit_roots.mutation.as_deref(),
+            EdgeKind::Mutation,
+        );
+        add_root_edge(
+            data,
+            schema_index,
+            implicit_roots.subscription.as_deref(),
+            EdgeKind::Subscription,
+        );
+
+        return;
+    }
+
+    for (schema_index, roots) in declared_roots {
+        add_root_edge(data, schema_index, roots.query.as_deref(), EdgeKind::Query);
+        add_root_edge(
+            data,
+            schema_index,
+            roots.mutation.as_deref(),
+            EdgeKind::Mutation,
+        );
+        add_root_edge(
+            data,
+            schema_index,
+            roots.subscription.as_deref(),
+            EdgeKind::Subscription,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node_ids(data: &Data) -> Vec<String> {
+        let mut ids: Vec<String> = data
+            .graph
+            .node_indices()
+            .map(|index| data.graph[index].id.clone())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// The parallel-parse rewrite is only safe if merging the per-file
+    /// results still produces the same node ids a serial run would have,
+    /// `Ext` suffix included.
+    #[test]
+    fn add_nodes_from_files_preserves_ids() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("a.graphql"),
+            String::from("type Foo { bar: String }"),
+        );
+        files.insert(
+            PathBuf::from("b.graphql"),
+            String::from("type Bar { baz: String }\nextend type Bar { qux: String }"),
+        );
+
+        let ids = task::block_on(async {
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            add_nodes_from_files(&shared_data, files).await.unwrap();
+            let data = shared_data.lock().await;
+            node_ids(&data)
+        });
+
+        assert_eq!(ids, vec!["Bar", "BarExt", "Foo"]);
+    }
+
+    fn root_id(data: &Data, kind: EdgeKind) -> Option<String> {
+        data.graph.edge_indices().find_map(|edge| {
+            let (_, target) = data.graph.edge_endpoints(edge)?;
+            (data.graph[edge] == kind).then(|| data.graph[target].id.clone())
+        })
+    }
+
+    fn schema_node_count(data: &Data) -> usize {
+        data.graph
+            .node_indices()
+            .filter(|index| data.graph[*index].entity.graphql == GraphQL::Schema)
+            .count()
+    }
+
+    #[test]
+    fn resolve_root_operations_wires_explicit_schema() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("schema.graphql"),
+            String::from(
+                "schema { query: Query, mutation: Mutation, subscription: Sub }\n\
+                 type Query { hello: String }\n\
+                 type Mutation { doIt: String }\n\
+                 type Sub { tick: String }",
+            ),
+        );
+
+        let data = task::block_on(async {
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            add_nodes_from_files(&shared_data, files).await.unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+            let query = root_id(&data, EdgeKind::Query);
+            let mutation = root_id(&data, EdgeKind::Mutation);
+            let subscription_root = data
+                .subscription_root()
+                .map(|index| data.graph[index].id.clone());
+            (query, mutation, subscription_root)
+        });
+
+        assert_eq!(
+            data,
+            (
+                Some(String::from("Query")),
+                Some(String::from("Mutation")),
+                Some(String::from("Sub"))
+            )
+        );
+    }
+
+    /// When no file declares a `schema { ... }` block, the conventionally
+    /// named `Query`/`Mutation`/`Subscription` types become the implicit
+    /// roots, per the GraphQL spec's defaults.
+    #[test]
+    fn resolve_root_operations_synthesizes_implicit_schema() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("types.graphql"),
+            String::from(
+                "type Query { hello: String }\n\
+                 type Mutation { doIt: String }\n\
+                 type Subscription { tick: String }",
+            ),
+        );
+
+        let (root_ids, schema_count) = task::block_on(async {
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            add_nodes_from_files(&shared_data, files).await.unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+
+            let root_ids = (
+                root_id(&data, EdgeKind::Query),
+                root_id(&data, EdgeKind::Mutation),
+                data.subscription_root()
+                    .map(|index| data.graph[index].id.clone()),
+            );
+            (root_ids, schema_node_count(&data))
+        });
+
+        assert_eq!(
+            root_ids,
+            (
+                Some(String::from("Query")),
+                Some(String::from("Mutation")),
+                Some(String::from("Subscription")),
+            )
+        );
+        assert_eq!(schema_count, 1);
+    }
+
+    /// Regression test for the chunk0-5 review fix: a synthetic schema node
+    /// synthesized on one rebuild must be replaced, not left dangling
+    /// alongside, once a later rebuild sees a real `schema { ... }` block.
+    #[test]
+    fn resolve_root_operations_replaces_synthetic_schema_with_explicit_one() {
+        let shared_data = Arc::new(Mutex::new(Data::default()));
+
+        let mut types_only = HashMap::new();
+        types_only.insert(
+            PathBuf::from("types.graphql"),
+            String::from(
+                "type Query { hello: String }\n\
+                 type Mutation { doIt: String }\n\
+                 type Subscription { tick: String }",
+            ),
+        );
+
+        task::block_on(async {
+            add_nodes_from_files(&shared_data, types_only)
+                .await
+                .unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+            assert_eq!(schema_node_count(&data), 1);
+        });
+
+        let mut explicit_schema = HashMap::new();
+        explicit_schema.insert(
+            PathBuf::from("schema.graphql"),
+            String::from("schema { query: Query, mutation: Mutation, subscription: Subscription }"),
+        );
+
+        task::block_on(async {
+            add_nodes_from_files(&shared_data, explicit_schema)
+                .await
+                .unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+        });
+
+        let data = task::block_on(shared_data.lock());
+        assert_eq!(schema_node_count(&data), 1);
+
+        let schema_index = data
+            .graph
+            .node_indices()
+            .find(|index| data.graph[*index].entity.graphql == GraphQL::Schema)
+            .unwrap();
+        assert_eq!(
+            data.graph[schema_index].entity.file,
+            PathBuf::from("schema.graphql")
+        );
+    }
 }
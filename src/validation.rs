@@ -0,0 +1,367 @@
+use crate::state::{Data, GraphQL};
+
+use async_std::path::PathBuf;
+use graphql_parser::Pos;
+use petgraph::Direction;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Type names that are allowed to be referenced by nothing even without an
+/// explicit `schema { ... }` block defining them as a root operation type.
+const ROOT_OPERATION_TYPES: [&str; 3] = ["Query", "Mutation", "Subscription"];
+
+/// GraphQL's built-in scalars. No node is ever created for these (there's no
+/// file to parse them out of), so they're exempt from "unresolved reference"
+/// reporting.
+const BUILTIN_SCALARS: [&str; 5] = ["String", "Int", "Float", "Boolean", "ID"];
+
+/// A single problem found while validating the dependency graph.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    /// A node depends on a type name that no node in the graph defines.
+    UnresolvedReference {
+        name: String,
+        referenced_by: String,
+        file: PathBuf,
+        position: Option<Pos>,
+    },
+    /// Two nodes in different files were inserted under the same id.
+    DuplicateDefinition {
+        id: String,
+        locations: Vec<(PathBuf, Option<Pos>)>,
+    },
+    /// A type that nothing references (no outgoing dependency edges, since
+    /// those point from the referenced type to the referencer) and isn't a
+    /// schema, root operation type, or type extension (extensions are stored
+    /// under their own `{Name}Ext` id, which no dependency ever names).
+    OrphanType {
+        name: String,
+        file: PathBuf,
+        position: Option<Pos>,
+    },
+}
+
+/// Renders `file`, followed by `:line:column` when a source position for the
+/// definition is known (synthesized nodes, e.g. the implicit schema, have
+/// none).
+fn format_location(file: &PathBuf, position: &Option<Pos>) -> String {
+    match position {
+        Some(position) => format!("{}:{}:{}", file.display(), position.line, position.column),
+        None => file.display().to_string(),
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::UnresolvedReference {
+                name,
+                referenced_by,
+                file,
+                position,
+            } => write!(
+                f,
+                "{}: `{}` references unknown type `{}`",
+                format_location(file, position),
+                referenced_by,
+                name
+            ),
+            Diagnostic::DuplicateDefinition { id, locations } => write!(
+                f,
+                "`{}` is defined more than once: {}",
+                id,
+                locations
+                    .iter()
+                    .map(|(file, position)| format_location(file, position))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Diagnostic::OrphanType {
+                name,
+                file,
+                position,
+            } => write!(
+                f,
+                "{}: `{}` is never referenced",
+                format_location(file, position),
+                name
+            ),
+        }
+    }
+}
+
+/// Walk the populated graph and report unresolved references, duplicate
+/// definitions, and orphan types, so `craftql` can double as a CI schema
+/// linter.
+pub fn validate(data: &Data) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    diagnostics.extend(find_unresolved_references(data));
+    diagnostics.extend(find_duplicate_definitions(data));
+    diagnostics.extend(find_orphan_types(data));
+
+    diagnostics
+}
+
+fn find_unresolved_references(data: &Data) -> Vec<Diagnostic> {
+    data.graph
+        .node_indices()
+        .flat_map(|index| {
+            let node = &data.graph[index];
+
+            node.entity
+                .dependencies
+                .iter()
+                .filter(|dependency| {
+                    !BUILTIN_SCALARS.contains(&dependency.as_str())
+                        && !data
+                            .graph
+                            .node_indices()
+                            .any(|other| &data.graph[other].id == *dependency)
+                })
+                .map(|dependency| Diagnostic::UnresolvedReference {
+                    name: dependency.clone(),
+                    referenced_by: node.id.clone(),
+                    file: node.entity.file.clone(),
+                    position: node.entity.position,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn find_duplicate_definitions(data: &Data) -> Vec<Diagnostic> {
+    let mut locations_by_id: HashMap<String, Vec<(PathBuf, Option<Pos>)>> = HashMap::new();
+
+    for index in data.graph.node_indices() {
+        let node = &data.graph[index];
+        locations_by_id
+            .entry(node.id.clone())
+            .or_default()
+            .push((node.entity.file.clone(), node.entity.position));
+    }
+
+    locations_by_id
+        .into_iter()
+        .filter(|(_, locations)| {
+            locations
+                .iter()
+                .map(|(file, _)| file)
+                .collect::<HashSet<_>>()
+                .len()
+                > 1
+        })
+        .map(|(id, locations)| Diagnostic::DuplicateDefinition { id, locations })
+        .collect()
+}
+
+fn find_orphan_types(data: &Data) -> Vec<Diagnostic> {
+    data.graph
+        .node_indices()
+        .filter(|index| {
+            let node = &data.graph[*index];
+
+            // Dependency edges point from the referenced type to the node
+            // that declared the dependency (see `rebuild_edges`), so "is
+            // this node referenced by anything" is its *outgoing* edges,
+            // not its incoming ones.
+            node.entity.graphql != GraphQL::Schema
+                && !matches!(node.entity.graphql, GraphQL::TypeExtension(_))
+                && !ROOT_OPERATION_TYPES.contains(&node.id.as_str())
+                && data
+                    .graph
+                    .neighbors_directed(*index, Direction::Outgoing)
+                    .count()
+                    == 0
+        })
+        .map(|index| {
+            let node = &data.graph[index];
+
+            Diagnostic::OrphanType {
+                name: node.id.clone(),
+                file: node.entity.file.clone(),
+                position: node.entity.position,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{add_nodes_from_files, rebuild_edges};
+
+    use async_std::sync::{Arc, Mutex};
+    use async_std::task;
+
+    #[test]
+    fn validate_reports_unresolved_duplicate_and_orphan() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("query.graphql"),
+            String::from("type Query { foo: Foo, bad: DoesNotExist }"),
+        );
+        files.insert(
+            PathBuf::from("foo_a.graphql"),
+            String::from("type Foo { name: String }"),
+        );
+        files.insert(
+            PathBuf::from("foo_b.graphql"),
+            String::from("type Foo { name: String }"),
+        );
+        files.insert(
+            PathBuf::from("orphan.graphql"),
+            String::from("type Orphan { id: ID }"),
+        );
+
+        let diagnostics = task::block_on(async {
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            add_nodes_from_files(&shared_data, files).await.unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+            validate(&data)
+        });
+
+        assert!(diagnostics.iter().any(|diagnostic| matches!(
+            diagnostic,
+            Diagnostic::UnresolvedReference { name, referenced_by, .. }
+                if name == "DoesNotExist" && referenced_by == "Query"
+        )));
+        assert!(diagnostics
+            .iter()
+            .any(|diagnostic| matches!(diagnostic, Diagnostic::DuplicateDefinition { id, .. } if id == "Foo")));
+        assert!(diagnostics
+            .iter()
+            .any(|diagnostic| matches!(diagnostic, Diagnostic::OrphanType { name, .. } if name == "Orphan")));
+
+        // Nothing references `Query` either, but it's a root operation
+        // type, so it must not be reported as an orphan.
+        assert!(!diagnostics
+            .iter()
+            .any(|diagnostic| matches!(diagnostic, Diagnostic::OrphanType { name, .. } if name == "Query")));
+    }
+
+    /// Regression test for the chunk0-2 review fix: built-in scalars have no
+    /// node in the graph by design, so a field typed `String` must not be
+    /// reported as an unresolved reference alongside a genuine one.
+    #[test]
+    fn validate_does_not_flag_builtin_scalars_as_unresolved() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("foo.graphql"),
+            String::from("type Foo { name: String, bad: DoesNotExist }"),
+        );
+
+        let diagnostics = task::block_on(async {
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            add_nodes_from_files(&shared_data, files).await.unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+            validate(&data)
+        });
+
+        assert!(!diagnostics.iter().any(|diagnostic| matches!(
+            diagnostic,
+            Diagnostic::UnresolvedReference { name, .. } if name == "String"
+        )));
+        assert!(diagnostics.iter().any(|diagnostic| matches!(
+            diagnostic,
+            Diagnostic::UnresolvedReference { name, .. } if name == "DoesNotExist"
+        )));
+    }
+
+    /// Regression test for the chunk0-2 review fix: dependency edges point
+    /// from the referenced type to the referencer, so a node's incoming
+    /// edges are what *it* depends on, not who depends on it. `B` is
+    /// referenced (by `A.b`) and must not be flagged; `A` is referenced by
+    /// nothing and must be.
+    #[test]
+    fn validate_orphan_direction_matches_edge_direction() {
+        let mut files = HashMap::new();
+        files.insert(PathBuf::from("a.graphql"), String::from("type A { b: B }"));
+        files.insert(
+            PathBuf::from("b.graphql"),
+            String::from("type B { id: ID }"),
+        );
+
+        let diagnostics = task::block_on(async {
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            add_nodes_from_files(&shared_data, files).await.unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+            validate(&data)
+        });
+
+        assert!(!diagnostics.iter().any(
+            |diagnostic| matches!(diagnostic, Diagnostic::OrphanType { name, .. } if name == "B")
+        ));
+        assert!(diagnostics.iter().any(
+            |diagnostic| matches!(diagnostic, Diagnostic::OrphanType { name, .. } if name == "A")
+        ));
+    }
+
+    /// Regression test for the chunk0-2 review fix: an `extend type` node is
+    /// stored under its `{Name}Ext` id, which nothing ever depends on by
+    /// construction, so it must not be flagged as an orphan.
+    #[test]
+    fn validate_does_not_flag_type_extensions_as_orphans() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("foo.graphql"),
+            String::from("type Foo { name: String }\nextend type Foo { extra: String }"),
+        );
+
+        let diagnostics = task::block_on(async {
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            add_nodes_from_files(&shared_data, files).await.unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+            validate(&data)
+        });
+
+        assert!(!diagnostics
+            .iter()
+            .any(|diagnostic| matches!(diagnostic, Diagnostic::OrphanType { name, .. } if name == "FooExt")));
+    }
+
+    /// Regression test for the chunk0-3 review fix: asserts a concrete
+    /// line/column, not just that some diagnostic fired, so the
+    /// `format_location`/`file:line:col` rendering has real coverage.
+    #[test]
+    fn validate_reports_concrete_source_position() {
+        let mut files = HashMap::new();
+        files.insert(
+            PathBuf::from("foo.graphql"),
+            String::from("\ntype Foo { bad: DoesNotExist }\n"),
+        );
+
+        let diagnostics = task::block_on(async {
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            add_nodes_from_files(&shared_data, files).await.unwrap();
+            let mut data = shared_data.lock().await;
+            rebuild_edges(&mut data);
+            validate(&data)
+        });
+
+        let diagnostic = diagnostics
+            .iter()
+            .find(|diagnostic| {
+                matches!(diagnostic, Diagnostic::UnresolvedReference { name, .. } if name == "DoesNotExist")
+            })
+            .expect("unresolved reference diagnostic");
+
+        assert_eq!(
+            *diagnostic,
+            Diagnostic::UnresolvedReference {
+                name: String::from("DoesNotExist"),
+                referenced_by: String::from("Foo"),
+                file: PathBuf::from("foo.graphql"),
+                position: Some(Pos { line: 2, column: 1 }),
+            }
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "foo.graphql:2:1: `Foo` references unknown type `DoesNotExist`"
+        );
+    }
+}
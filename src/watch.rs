@@ -0,0 +1,207 @@
+use crate::state::Data;
+use crate::utils::{add_nodes_from_files, get_files, rebuild_edges};
+
+use anyhow::Result;
+use async_std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    task,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+fn hash_contents(contents: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Keeps `craftql` running and rebuilds the graph incrementally as files
+/// under `path` change, instead of re-parsing the whole tree on every edit.
+///
+/// Each poll re-walks `path` and compares every file's contents against a
+/// `HashMap<PathBuf, u64>` of previously-seen hashes, the same idea as
+/// rustdoc's hashed-artifact cache in `write_shared`. Only files whose hash
+/// changed are re-parsed; their stale nodes are dropped first (tracked via
+/// `Entity.file`), and edges are rebuilt once at the end.
+pub async fn watch(
+    path: PathBuf,
+    shared_data: Arc<Mutex<Data>>,
+    poll_interval: Duration,
+) -> Result<()> {
+    let mut file_hashes: HashMap<PathBuf, u64> = HashMap::new();
+
+    println!(
+        "Watching {} for changes (polling every {}s)...",
+        path.display(),
+        poll_interval.as_secs()
+    );
+
+    loop {
+        // A single bad poll (a file deleted mid-edit, a transient I/O error)
+        // shouldn't take down a long-lived dev server - log it and try again
+        // next tick instead of propagating out of the loop.
+        match rebuild_changed_files(&path, &shared_data, &mut file_hashes).await {
+            Ok(true) => println!("Rebuilt graph."),
+            Ok(false) => {}
+            Err(error) => eprintln!("Skipping this poll, rebuild failed: {}", error),
+        }
+
+        task::sleep(poll_interval).await;
+    }
+}
+
+async fn rebuild_changed_files(
+    path: &PathBuf,
+    shared_data: &Arc<Mutex<Data>>,
+    file_hashes: &mut HashMap<PathBuf, u64>,
+) -> Result<bool> {
+    let previously_seen: Vec<PathBuf> = file_hashes.keys().cloned().collect();
+
+    {
+        let mut data = shared_data.lock().await;
+        data.files.clear();
+    }
+    get_files(path.clone(), shared_data.clone()).await?;
+
+    let current_files = shared_data.lock().await.files.clone();
+
+    let changed_files: HashMap<PathBuf, String> = current_files
+        .iter()
+        .filter(|(file, contents)| file_hashes.get(*file) != Some(&hash_contents(contents)))
+        .map(|(file, contents)| (file.clone(), contents.clone()))
+        .collect();
+
+    let removed_files: Vec<PathBuf> = previously_seen
+        .into_iter()
+        .filter(|file| !current_files.contains_key(file))
+        .collect();
+
+    if changed_files.is_empty() && removed_files.is_empty() {
+        return Ok(false);
+    }
+
+    let stale_files: Vec<PathBuf> = changed_files
+        .keys()
+        .cloned()
+        .chain(removed_files.iter().cloned())
+        .collect();
+
+    remove_stale_nodes(shared_data, &stale_files).await;
+    add_nodes_from_files(shared_data, changed_files.clone()).await?;
+
+    {
+        let mut data = shared_data.lock().await;
+        rebuild_edges(&mut data);
+    }
+
+    for (file, contents) in &changed_files {
+        file_hashes.insert(file.clone(), hash_contents(contents));
+    }
+    for file in &removed_files {
+        file_hashes.remove(file);
+    }
+
+    Ok(true)
+}
+
+/// Removes every node owned by one of `stale_files`, one at a time. Node
+/// indices shift when a node is removed (petgraph swap-removes), so the
+/// stale set is re-queried fresh after each removal rather than collected
+/// once upfront.
+async fn remove_stale_nodes(shared_data: &Arc<Mutex<Data>>, stale_files: &[PathBuf]) {
+    let mut data = shared_data.lock().await;
+
+    while let Some(index) = data
+        .graph
+        .node_indices()
+        .find(|index| stale_files.contains(&data.graph[*index].entity.file))
+    {
+        data.graph.remove_node(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_std::fs;
+
+    fn node_exists(data: &Data, id: &str) -> bool {
+        data.graph
+            .node_indices()
+            .any(|index| data.graph[index].id == id)
+    }
+
+    /// Exercises a multi-poll sequence against real files on disk: an
+    /// initial rebuild, a no-op poll, an edit, and a deletion, checking the
+    /// graph (and the content-hash cache) after each one.
+    #[test]
+    fn rebuild_changed_files_tracks_edits_and_deletes_across_polls() {
+        task::block_on(async {
+            let dir = PathBuf::from(std::env::temp_dir()).join(format!(
+                "craftql-watch-test-{}-{}",
+                std::process::id(),
+                hash_contents("rebuild_changed_files_tracks_edits_and_deletes_across_polls")
+            ));
+            fs::create_dir_all(&dir).await.unwrap();
+
+            let file_a = dir.join("a.graphql");
+            let file_b = dir.join("b.graphql");
+            fs::write(&file_a, "type Foo { id: ID }").await.unwrap();
+            fs::write(&file_b, "type Bar { id: ID }").await.unwrap();
+
+            let shared_data = Arc::new(Mutex::new(Data::default()));
+            let mut file_hashes: HashMap<PathBuf, u64> = HashMap::new();
+
+            // First poll picks up both files.
+            assert!(rebuild_changed_files(&dir, &shared_data, &mut file_hashes)
+                .await
+                .unwrap());
+            assert_eq!(file_hashes.len(), 2);
+            {
+                let data = shared_data.lock().await;
+                assert!(node_exists(&data, "Foo"));
+                assert!(node_exists(&data, "Bar"));
+            }
+
+            // Nothing changed, so the second poll is a no-op.
+            assert!(!rebuild_changed_files(&dir, &shared_data, &mut file_hashes)
+                .await
+                .unwrap());
+
+            // Editing a.graphql replaces its node rather than duplicating it.
+            fs::write(&file_a, "type Foo { id: ID, extra: String }")
+                .await
+                .unwrap();
+            assert!(rebuild_changed_files(&dir, &shared_data, &mut file_hashes)
+                .await
+                .unwrap());
+            {
+                let data = shared_data.lock().await;
+                assert_eq!(
+                    data.graph
+                        .node_indices()
+                        .filter(|index| data.graph[*index].id == "Foo")
+                        .count(),
+                    1
+                );
+            }
+
+            // Deleting b.graphql drops its node and its cached hash.
+            fs::remove_file(&file_b).await.unwrap();
+            assert!(rebuild_changed_files(&dir, &shared_data, &mut file_hashes)
+                .await
+                .unwrap());
+            {
+                let data = shared_data.lock().await;
+                assert!(!node_exists(&data, "Bar"));
+            }
+            assert!(!file_hashes.contains_key(&file_b));
+
+            fs::remove_dir_all(&dir).await.unwrap();
+        });
+    }
+}